@@ -0,0 +1,103 @@
+use tokio::runtime::Runtime;
+
+use crate::{external_types, S3ObjectUri, S3ReaderError};
+
+/// The keys and common prefixes found under an S3 prefix
+///
+/// See [`S3Directory::list`].
+#[derive(Debug, Clone, Default)]
+pub struct S3Directory {
+    keys: Vec<String>,
+    prefixes: Vec<String>,
+}
+
+impl S3Directory {
+    /// Lists the keys and common prefixes immediately under the prefix of `uri`
+    ///
+    /// `uri` is treated as a prefix (e.g. `s3://bucket/path/to/dir/`); results are delimited on
+    /// `/`, so nested "sub-directories" are reported in [`S3Directory::prefixes`] rather than
+    /// flattened into [`S3Directory::keys`]. A `ListObjectsV2` continuation token is followed
+    /// transparently until all pages of results have been fetched.
+    ///
+    /// This constructs a dedicated Tokio runtime for the duration of the call, the same way
+    /// [`crate::S3Reader::new`] does.
+    pub fn list(uri: &S3ObjectUri) -> Result<S3Directory, S3ReaderError> {
+        let runtime = Runtime::new().unwrap();
+        let config = runtime.block_on(aws_config::load_from_env());
+        let client = aws_sdk_s3::Client::new(&config);
+        runtime.block_on(S3Directory::list_with_client(&client, uri))
+    }
+
+    /// Lists the keys and common prefixes immediately under the prefix of `uri`, using an
+    /// existing `aws_sdk_s3::Client`
+    ///
+    /// See [`S3Directory::list`].
+    pub async fn list_with_client(
+        client: &aws_sdk_s3::Client,
+        uri: &S3ObjectUri,
+    ) -> Result<S3Directory, S3ReaderError> {
+        let mut keys = Vec::new();
+        let mut prefixes = Vec::new();
+        let mut continuation_token = None;
+
+        loop {
+            let mut request = client
+                .list_objects_v2()
+                .bucket(uri.bucket())
+                .prefix(uri.key())
+                .delimiter("/");
+            if let Some(token) = continuation_token {
+                request = request.continuation_token(token);
+            }
+            let output: external_types::ListObjectsV2Output = request.send().await?;
+
+            keys.extend(
+                output
+                    .contents()
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|object| object.key().map(str::to_string)),
+            );
+            prefixes.extend(
+                output
+                    .common_prefixes()
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|common_prefix| common_prefix.prefix().map(str::to_string)),
+            );
+
+            if output.is_truncated() {
+                continuation_token = Some(
+                    output
+                        .next_continuation_token()
+                        .map(str::to_string)
+                        .ok_or_else(|| {
+                            S3ReaderError::ObjectNotFetched(
+                                "ListObjectsV2 response is truncated but has no continuation token"
+                                    .to_string(),
+                            )
+                        })?,
+                );
+            } else {
+                break;
+            }
+        }
+
+        Ok(S3Directory { keys, prefixes })
+    }
+
+    /// Returns the keys of the objects found directly under the listed prefix
+    pub fn keys(&self) -> &[String] {
+        &self.keys
+    }
+
+    /// Returns the common prefixes (the "sub-directories") found directly under the listed prefix
+    pub fn prefixes(&self) -> &[String] {
+        &self.prefixes
+    }
+
+    /// Returns whether the listed prefix has any children, i.e. whether it behaves like a directory
+    pub fn is_dir(&self) -> bool {
+        !self.keys.is_empty() || !self.prefixes.is_empty()
+    }
+}
@@ -2,8 +2,23 @@
 
 use bytes::Buf;
 use std::io::{Read, Seek, SeekFrom};
+use std::pin::Pin;
+use std::time::Duration;
 use thiserror::Error;
-use tokio::runtime::Runtime;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::runtime::{Handle, Runtime};
+
+mod zstd_reader;
+pub use zstd_reader::S3ZstdReader;
+
+mod metadata;
+pub use metadata::{FileType, Metadata, Permissions};
+
+mod directory;
+pub use directory::S3Directory;
+
+mod writer;
+pub use writer::{S3Writer, S3WriterError};
 
 
 /// Re-exported types from `aws_sdk_s3` and `aws_types`
@@ -11,9 +26,17 @@ pub mod external_types {
     pub use aws_types::sdk_config::SdkConfig;
     pub use aws_sdk_s3::types::SdkError;
     pub use aws_sdk_s3::types::AggregatedBytes;
+    pub use aws_sdk_s3::types::DateTime;
+    pub use aws_sdk_s3::types::ByteStream;
+    pub use aws_sdk_s3::output::GetObjectOutput;
     pub use aws_sdk_s3::output::HeadObjectOutput;
+    pub use aws_sdk_s3::output::ListObjectsV2Output;
     pub use aws_sdk_s3::error::GetObjectError;
     pub use aws_sdk_s3::error::HeadObjectError;
+    pub use aws_sdk_s3::error::ListObjectsV2Error;
+    pub use aws_sdk_s3::model::StorageClass;
+    pub use aws_sdk_s3::model::CompletedPart;
+    pub use aws_sdk_s3::model::CompletedMultipartUpload;
 }
 
 #[derive(Error, Debug)]
@@ -28,6 +51,10 @@ pub enum S3ReaderError {
     InvalidContent,
     #[error("invalid read range {0}-{1}")]
     InvalidRange(u64, u64),
+    #[error("invalid zstd seek table: {0}")]
+    InvalidSeekTable(String),
+    #[error("gave up after {0} attempts: {1}")]
+    RetriesExhausted(u32, String),
 }
 
 impl From<external_types::SdkError<external_types::GetObjectError>> for S3ReaderError {
@@ -36,6 +63,86 @@ impl From<external_types::SdkError<external_types::GetObjectError>> for S3Reader
     }
 }
 
+impl From<external_types::SdkError<external_types::HeadObjectError>> for S3ReaderError {
+    fn from(err: external_types::SdkError<external_types::HeadObjectError>) -> S3ReaderError {
+        S3ReaderError::ObjectNotFetched(err.to_string())
+    }
+}
+
+impl From<external_types::SdkError<external_types::ListObjectsV2Error>> for S3ReaderError {
+    fn from(err: external_types::SdkError<external_types::ListObjectsV2Error>) -> S3ReaderError {
+        S3ReaderError::ObjectNotFetched(err.to_string())
+    }
+}
+
+/// Error codes that S3 returns for transient conditions worth retrying
+fn is_retryable_code(code: Option<&str>) -> bool {
+    matches!(
+        code,
+        Some("RequestTimeout")
+            | Some("Throttling")
+            | Some("ThrottlingException")
+            | Some("SlowDown")
+            | Some("InternalError")
+            | Some("ServiceUnavailable")
+    )
+}
+
+/// Whether a `GetObject` failure is transient and worth retrying (5xx, throttling, timeout, or a
+/// dropped connection), as opposed to e.g. a 404/403 which fails immediately
+fn is_retryable_get_error(err: &external_types::SdkError<external_types::GetObjectError>) -> bool {
+    use aws_sdk_s3::error::ProvideErrorMetadata;
+    match err {
+        external_types::SdkError::TimeoutError(_)
+        | external_types::SdkError::DispatchFailure(_)
+        | external_types::SdkError::ResponseError(_) => true,
+        external_types::SdkError::ServiceError(context) => is_retryable_code(context.err().code()),
+        _ => false,
+    }
+}
+
+/// Whether a `HeadObject` failure is transient and worth retrying, see [`is_retryable_get_error`]
+fn is_retryable_head_error(err: &external_types::SdkError<external_types::HeadObjectError>) -> bool {
+    use aws_sdk_s3::error::ProvideErrorMetadata;
+    match err {
+        external_types::SdkError::TimeoutError(_)
+        | external_types::SdkError::DispatchFailure(_)
+        | external_types::SdkError::ResponseError(_) => true,
+        external_types::SdkError::ServiceError(context) => is_retryable_code(context.err().code()),
+        _ => false,
+    }
+}
+
+/// Retry and timeout policy applied around each `GetObject`/`HeadObject` call
+///
+/// A transient failure (5xx, throttling, a connect/read timeout, or a dropped body stream) is
+/// retried up to `max_single_read_retries` times with exponentially growing backoff, starting
+/// at `retry_initial_backoff_ms`. Non-retryable errors (e.g. 404/403) are returned immediately.
+/// See [`S3Reader::with_retry_policy`].
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// Maximum number of retries for a single `GetObject`/`HeadObject` call, after the initial attempt
+    pub max_single_read_retries: u32,
+    /// Initial backoff before the first retry; doubles after each subsequent retry
+    pub retry_initial_backoff_ms: u64,
+    /// Timeout for sending a request and receiving its headers
+    pub connect_timeout_ms: u64,
+    /// Timeout for collecting the full body of a `GetObject` response in `read_range`, and for
+    /// each single read from the body stream kept open by `Read::read`
+    pub read_timeout_ms: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            max_single_read_retries: 3,
+            retry_initial_backoff_ms: 100,
+            connect_timeout_ms: 10_000,
+            read_timeout_ms: 30_000,
+        }
+    }
+}
+
 impl From<S3ReaderError> for std::io::Error {
     fn from(error: S3ReaderError) -> std::io::Error {
         std::io::Error::new(std::io::ErrorKind::InvalidData, error)
@@ -100,6 +207,74 @@ impl S3ObjectUri {
     pub fn key(&self) -> &str {
         &self.key
     }
+
+    /// Returns whether this URI refers to a directory-like prefix rather than a single object
+    ///
+    /// By convention, a key ending in `/` (e.g. `s3://mybucket/path/to/dir/`) is a prefix; see
+    /// [`S3Directory::list`] to enumerate the objects and common prefixes under it.
+    ///
+    /// # Example
+    /// ```
+    /// use s3reader::S3ObjectUri;
+    /// let uri = S3ObjectUri::new("s3://mybucket/path/to/dir/").unwrap();
+    ///
+    /// assert!(uri.is_prefix());
+    /// ```
+    pub fn is_prefix(&self) -> bool {
+        self.key.is_empty() || self.key.ends_with('/')
+    }
+}
+
+/// The Tokio runtime backing the blocking calls of an [`S3Reader`]
+///
+/// `S3Reader` needs a way to drive `async` AWS SDK calls from its synchronous `Read`/`Seek`
+/// API. Either it owns a dedicated multithreaded runtime (the default, see
+/// [`S3Reader::new`]/[`S3Reader::from_config`]), or it shares a [`Handle`] to a runtime a
+/// caller already owns (see [`S3Reader::from_config_with_runtime`]), so that it reuses that
+/// runtime's thread pool instead of spinning up a second one.
+///
+/// Either way, `Handle::block_on` panics when called from a task that's already being driven
+/// by *any* Tokio runtime — this isn't limited to nesting the same runtime inside itself, any
+/// async execution context triggers it. So `S3Reader`'s synchronous methods must only ever be
+/// called from a plain blocking thread (e.g. via `tokio::task::spawn_blocking`), never directly
+/// from inside an `async fn`, even one running on the runtime behind this `Handle`.
+pub(crate) enum RuntimeRef {
+    Owned(Runtime),
+    Shared(Handle),
+}
+
+impl RuntimeRef {
+    pub(crate) fn handle(&self) -> Handle {
+        match self {
+            RuntimeRef::Owned(runtime) => runtime.handle().clone(),
+            RuntimeRef::Shared(handle) => handle.clone(),
+        }
+    }
+}
+
+/// The size of each ranged `GetObject` opened for sequential reads, see [`S3Reader::with_buffer_capacity`]
+const DEFAULT_BUFFER_CAPACITY: u64 = 1024 * 1024;
+
+/// A `GetObject` body stream kept open across `read` calls to serve sequential reads, rather than
+/// re-fetching on every call
+///
+/// See [`S3Reader::with_buffer_capacity`]
+struct ActiveBody {
+    /// The still-open body of a ranged `GetObject` covering `[pos, end)`
+    stream: Pin<Box<dyn AsyncRead + Send>>,
+    /// The object offset of the next byte `stream` will yield
+    pos: u64,
+    /// The exclusive end of the range `stream` was opened for
+    end: u64,
+}
+
+impl ActiveBody {
+    /// Whether `pos` falls within this stream's still-unread window, i.e. a `read` at `pos` can
+    /// be served by this stream (skipping forward first, if `pos` is past `self.pos`) rather
+    /// than opening a new ranged `GetObject`
+    fn contains(&self, pos: u64) -> bool {
+        pos >= self.pos && pos < self.end
+    }
 }
 
 /// A Reader for S3 objects that implements the `Read` and `Seek` traits
@@ -125,6 +300,10 @@ pub struct S3Reader {
     uri: S3ObjectUri,
     pos: u64,
     header: Option<external_types::HeadObjectOutput>,
+    runtime: RuntimeRef,
+    buffer_capacity: u64,
+    body: Option<ActiveBody>,
+    retry_policy: RetryPolicy,
 }
 
 impl S3Reader {
@@ -142,11 +321,15 @@ impl S3Reader {
     ///
     /// This method does not check for presence of an actual object in S3 or for connectivity.
     /// Use [`S3Reader::open`] instead to ensure that the S3 object actually exists.
+    ///
+    /// This constructs and owns a dedicated Tokio runtime for the lifetime of the reader. If you
+    /// already run a Tokio runtime and want to reuse its thread pool instead of spinning up a
+    /// second one, use [`S3Reader::from_config_with_runtime`] instead — see its docs for the
+    /// constraint on where the reader's blocking methods can then be called from.
     pub fn new(uri: S3ObjectUri) -> S3Reader {
-        let config = Runtime::new()
-            .unwrap()
-            .block_on(aws_config::load_from_env());
-        S3Reader::from_config(&config, uri)
+        let runtime = Runtime::new().unwrap();
+        let config = runtime.block_on(aws_config::load_from_env());
+        S3Reader::build(&config, uri, RuntimeRef::Owned(runtime))
     }
 
     /// Creates a new `S3Reader` and checks for presence of the S3 object
@@ -156,26 +339,209 @@ impl S3Reader {
     /// object is actually available and thus prevents possible runtime errors.
     pub fn open(uri: S3ObjectUri) -> Result<S3Reader, S3ReaderError> {
         let mut reader = S3Reader::new(uri);
-        match Runtime::new().unwrap().block_on(reader.fetch_header()) {
-            Err(err) => Err(S3ReaderError::ObjectNotFetched(err.to_string())),
-            Ok(_) => Ok(reader),
-        }
+        let handle = reader.runtime.handle();
+        handle.block_on(reader.fetch_header())?;
+        Ok(reader)
     }
 
     /// Creates a new `S3Reader` with a custom AWS `SdkConfig`
     ///
     /// This method is useful if you don't want to use the default configbuilder using the environment.
     /// It does not check for correctness, connectivity to the S3 bucket or presence of the S3 object.
+    ///
+    /// This constructs and owns a dedicated Tokio runtime for the lifetime of the reader. If you
+    /// already run a Tokio runtime and want to reuse its thread pool instead of spinning up a
+    /// second one, use [`S3Reader::from_config_with_runtime`] instead — see its docs for the
+    /// constraint on where the reader's blocking methods can then be called from.
     pub fn from_config(config: &external_types::SdkConfig, uri: S3ObjectUri) -> S3Reader {
+        let runtime = Runtime::new().unwrap();
+        S3Reader::build(config, uri, RuntimeRef::Owned(runtime))
+    }
+
+    /// Creates a new `S3Reader` with a custom AWS `SdkConfig`, sharing a caller-supplied Tokio
+    /// runtime `Handle` instead of owning a dedicated runtime
+    ///
+    /// Use this constructor when you already run a Tokio runtime (e.g. inside a
+    /// `#[tokio::main]` application) and want `S3Reader` to reuse its thread pool rather than
+    /// spin up a second one.
+    ///
+    /// `Handle::block_on` panics when called from a task that's already being driven by *any*
+    /// Tokio runtime, not just this one. `S3Reader`'s synchronous `Read`/`Seek` methods call
+    /// `block_on` internally, so they must only be invoked from a plain blocking thread (e.g.
+    /// via `tokio::task::spawn_blocking`) — never directly from inside an `async fn`, even one
+    /// running on the runtime behind `handle`.
+    pub fn from_config_with_runtime(
+        config: &external_types::SdkConfig,
+        uri: S3ObjectUri,
+        handle: Handle,
+    ) -> S3Reader {
+        S3Reader::build(config, uri, RuntimeRef::Shared(handle))
+    }
+
+    fn build(config: &external_types::SdkConfig, uri: S3ObjectUri, runtime: RuntimeRef) -> S3Reader {
         let client = aws_sdk_s3::Client::new(config);
         S3Reader {
             client,
             uri,
             pos: 0,
             header: None,
+            runtime,
+            buffer_capacity: DEFAULT_BUFFER_CAPACITY,
+            body: None,
+            retry_policy: RetryPolicy::default(),
         }
     }
 
+    /// Sets the retry and timeout policy applied around each `GetObject`/`HeadObject` call
+    ///
+    /// # Example
+    /// ```no_run
+    /// use s3reader::{RetryPolicy, S3Reader};
+    /// use s3reader::S3ObjectUri;
+    ///
+    /// let uri = S3ObjectUri::new("s3://my-bucket/path/to/huge/file").unwrap();
+    /// let reader = S3Reader::open(uri).unwrap().with_retry_policy(RetryPolicy {
+    ///     max_single_read_retries: 5,
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> S3Reader {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Sets the size of the ranged `GetObject` opened for sequential reads, in bytes
+    ///
+    /// `S3Reader` keeps one `GetObject` body stream open and drains it as `read` is called
+    /// repeatedly, instead of issuing a fresh request for every call. Once that stream is
+    /// exhausted, the next `read` opens a new ranged request of `capacity` bytes starting at the
+    /// current position. After a `Seek` that lands outside the currently open stream's window,
+    /// the next `read` drops that stream and opens a new one at the seeked-to position. Pass `0` to disable this
+    /// and fetch exactly the requested bytes on every `read`, as before.
+    ///
+    /// Defaults to 1 MiB.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use s3reader::S3Reader;
+    /// use s3reader::S3ObjectUri;
+    ///
+    /// let uri = S3ObjectUri::new("s3://my-bucket/path/to/huge/file").unwrap();
+    /// let reader = S3Reader::open(uri).unwrap().with_buffer_capacity(8 * 1024 * 1024);
+    /// ```
+    pub fn with_buffer_capacity(mut self, capacity: u64) -> S3Reader {
+        self.buffer_capacity = capacity;
+        self.body = None;
+        self
+    }
+
+    /// Ensures a `GetObject` body stream is open and positioned at the reader's current cursor
+    ///
+    /// Reuses the stream already open in `self.body` if the cursor still falls within the range
+    /// it was opened for, skipping forward over any bytes a `Seek` jumped past. Otherwise (no
+    /// stream open, the open one was exhausted, or the cursor moved outside its window) opens a
+    /// new ranged `GetObject` starting at the cursor.
+    fn ensure_body(&mut self) -> Result<(), std::io::Error> {
+        if let Some(body) = &self.body {
+            if body.contains(self.pos) {
+                if self.pos > body.pos {
+                    self.skip_body(self.pos - body.pos)?;
+                }
+                return Ok(());
+            }
+        }
+        self.open_body()
+    }
+
+    /// Reads and discards `n` bytes from the front of the open body stream, to catch it up to a
+    /// forward `Seek` that landed inside its still-unread window
+    fn skip_body(&mut self, n: u64) -> Result<(), std::io::Error> {
+        let mut remaining = n;
+        let mut discard = [0u8; 8192];
+        while remaining > 0 {
+            let want = std::cmp::min(remaining, discard.len() as u64) as usize;
+            let read = self.read_body(&mut discard[..want])?;
+            if read == 0 {
+                break;
+            }
+            remaining -= read as u64;
+        }
+        Ok(())
+    }
+
+    /// Opens a new ranged `GetObject` body stream starting at the current cursor, replacing
+    /// whatever stream was previously open
+    fn open_body(&mut self) -> Result<(), std::io::Error> {
+        let len = self.len();
+        let end = std::cmp::min(self.pos + self.buffer_capacity, len);
+        self.open_body_range(self.pos, end)
+    }
+
+    /// Opens a new ranged `GetObject` body stream covering `[from, end)`, replacing whatever
+    /// stream was previously open
+    fn open_body_range(&mut self, from: u64, end: u64) -> Result<(), std::io::Error> {
+        let handle = self.runtime.handle();
+        let object_output = handle
+            .block_on(open_range_stream(
+                &self.client,
+                self.uri.bucket(),
+                self.uri.key(),
+                from,
+                end - 1,
+                &self.retry_policy,
+            ))
+            .map_err(std::io::Error::from)?;
+        self.body = Some(ActiveBody {
+            stream: Box::pin(object_output.body.into_async_read()),
+            pos: from,
+            end,
+        });
+        Ok(())
+    }
+
+    /// Reads from the currently open body stream into `buf`, retrying the same way
+    /// [`S3Reader::read_range`] does if the stream times out or errors partway through (e.g. a
+    /// dropped connection)
+    ///
+    /// The stream itself can't be resumed after an error, since it has no way to seek backward;
+    /// instead a failed read re-opens a ranged `GetObject` for the remainder of the window that
+    /// was still unread (`[body.pos, body.end)`) and resumes from there.
+    fn read_body(&mut self, buf: &mut [u8]) -> Result<usize, std::io::Error> {
+        let mut backoff_ms = self.retry_policy.retry_initial_backoff_ms;
+        for attempt in 0..=self.retry_policy.max_single_read_retries {
+            let handle = self.runtime.handle();
+            let read_timeout_ms = self.retry_policy.read_timeout_ms;
+            let body = self
+                .body
+                .as_mut()
+                .expect("read_body called without an open body stream");
+
+            let outcome = handle.block_on(tokio::time::timeout(
+                Duration::from_millis(read_timeout_ms),
+                body.stream.as_mut().read(buf),
+            ));
+
+            let message = match outcome {
+                Ok(Ok(n)) => {
+                    body.pos += n as u64;
+                    return Ok(n);
+                }
+                Ok(Err(err)) => err.to_string(),
+                Err(_) => "timed out reading GetObject body".to_string(),
+            };
+
+            if attempt == self.retry_policy.max_single_read_retries {
+                return Err(S3ReaderError::RetriesExhausted(attempt + 1, message).into());
+            }
+
+            let (from, end) = (body.pos, body.end);
+            handle.block_on(tokio::time::sleep(Duration::from_millis(backoff_ms)));
+            backoff_ms *= 2;
+            self.open_body_range(from, end)?;
+        }
+        unreachable!("the loop above always returns before exhausting its range")
+    }
+
     /// Returns A Future for the bytes read from the S3 object for the specified byte-range
     ///
     /// This method does not update the internal cursor position. To maintain
@@ -199,6 +565,10 @@ impl S3Reader {
     /// ).unwrap().into_bytes();
     /// assert_eq!(bytes.len(), 150);
     /// ```
+    ///
+    /// A transient failure (5xx, throttling, or a connect/read timeout) is retried with
+    /// exponential backoff according to the reader's [`RetryPolicy`]; see
+    /// [`S3Reader::with_retry_policy`]. The *same* byte range is re-issued on every retry.
     pub async fn read_range(
         &mut self,
         from: u64,
@@ -207,19 +577,32 @@ impl S3Reader {
         if to < from || from > self.len() {
             return Err(S3ReaderError::InvalidRange(from, to));
         }
-        let object_output = self
-            .client
-            .get_object()
-            .bucket(self.uri.bucket())
-            .key(self.uri.key())
-            .range(format!("bytes={}-{}", from, to))
-            .send()
-            .await?;
-
-        match object_output.body.collect().await {
-            Ok(x) => Ok(x),
-            Err(_) => Err(S3ReaderError::InvalidContent),
+
+        let mut backoff_ms = self.retry_policy.retry_initial_backoff_ms;
+        for attempt in 0..=self.retry_policy.max_single_read_retries {
+            match fetch_range_once(
+                &self.client,
+                self.uri.bucket(),
+                self.uri.key(),
+                from,
+                to,
+                self.retry_policy.connect_timeout_ms,
+                self.retry_policy.read_timeout_ms,
+            )
+            .await
+            {
+                Ok(bytes) => return Ok(bytes),
+                Err((false, err)) => return Err(err),
+                Err((true, err)) => {
+                    if attempt == self.retry_policy.max_single_read_retries {
+                        return Err(S3ReaderError::RetriesExhausted(attempt + 1, err.to_string()));
+                    }
+                    tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+                    backoff_ms *= 2;
+                }
+            }
         }
+        unreachable!("the loop above always returns before exhausting its range")
     }
 
     /// Returns the bytes read from the S3 object for the specified byte-range
@@ -247,7 +630,8 @@ impl S3Reader {
         from: u64,
         to: u64,
     ) -> Result<external_types::AggregatedBytes, S3ReaderError> {
-        Runtime::new().unwrap().block_on(self.read_range(from, to))
+        let handle = self.runtime.handle();
+        handle.block_on(self.read_range(from, to))
     }
 
     /// Fetches the object's header from S3
@@ -268,18 +652,35 @@ impl S3Reader {
     /// ).unwrap();
     /// assert_eq!(reader.len(), 150);
     /// ```
-    pub async fn fetch_header(
-        &mut self,
-    ) -> Result<(), external_types::SdkError<external_types::HeadObjectError>> {
-        let header = self
-            .client
-            .head_object()
-            .bucket(self.uri.bucket())
-            .key(self.uri.key())
-            .send()
-            .await?;
-        self.header = Some(header);
-        Ok(())
+    ///
+    /// A transient failure (5xx, throttling, or a connect timeout) is retried with exponential
+    /// backoff according to the reader's [`RetryPolicy`]; see [`S3Reader::with_retry_policy`].
+    pub async fn fetch_header(&mut self) -> Result<(), S3ReaderError> {
+        let mut backoff_ms = self.retry_policy.retry_initial_backoff_ms;
+        for attempt in 0..=self.retry_policy.max_single_read_retries {
+            match fetch_header_once(
+                &self.client,
+                self.uri.bucket(),
+                self.uri.key(),
+                self.retry_policy.connect_timeout_ms,
+            )
+            .await
+            {
+                Ok(header) => {
+                    self.header = Some(header);
+                    return Ok(());
+                }
+                Err((false, err)) => return Err(err),
+                Err((true, err)) => {
+                    if attempt == self.retry_policy.max_single_read_retries {
+                        return Err(S3ReaderError::RetriesExhausted(attempt + 1, err.to_string()));
+                    }
+                    tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+                    backoff_ms *= 2;
+                }
+            }
+        }
+        unreachable!("the loop above always returns before exhausting its range")
     }
 
     /// Returns the `content_length` of the S3 object
@@ -292,8 +693,8 @@ impl S3Reader {
         if let Some(header) = &self.header {
             u64::try_from(header.content_length()).unwrap()
         } else {
-            Runtime::new()
-                .unwrap()
+            let handle = self.runtime.handle();
+            handle
                 .block_on(self.fetch_header())
                 .expect("unable to determine the object size");
             self.len()
@@ -303,6 +704,20 @@ impl S3Reader {
     pub fn pos(&self) -> u64 {
         self.pos
     }
+
+    /// Returns [`Metadata`] about the S3 object, fetching the header first if necessary
+    ///
+    /// Modeled after [`std::fs::File::metadata`].
+    pub fn metadata(&mut self) -> Result<Metadata, S3ReaderError> {
+        if self.header.is_none() {
+            let handle = self.runtime.handle();
+            handle.block_on(self.fetch_header())?;
+        }
+        Ok(Metadata::new(
+            self.uri.key().to_string(),
+            self.header.clone().unwrap(),
+        ))
+    }
 }
 
 impl Read for S3Reader {
@@ -310,17 +725,29 @@ impl Read for S3Reader {
         if self.pos >= self.len() {
             return Ok(0);
         }
-        let end_pos = self.pos + buf.len() as u64;
 
-        // The `read_range` method uses inclusive byte ranges, we exclude the last byte
-        let s3_data = self.read_range_sync(self.pos, end_pos - 1)?;
+        if self.buffer_capacity == 0 {
+            let end_pos = self.pos + buf.len() as u64;
 
-        // Ensure that the position cursor is only increased by the number of actually read bytes
-        self.pos += u64::try_from(s3_data.remaining()).unwrap();
+            // The `read_range` method uses inclusive byte ranges, we exclude the last byte
+            let s3_data = self.read_range_sync(self.pos, end_pos - 1)?;
 
-        // Use the Reader provided by `AggregatedBytes` instead of converting manually
-        let mut reader = s3_data.reader();
-        reader.read(buf)
+            // Ensure that the position cursor is only increased by the number of actually read bytes
+            self.pos += u64::try_from(s3_data.remaining()).unwrap();
+
+            // Use the Reader provided by `AggregatedBytes` instead of converting manually
+            let mut reader = s3_data.reader();
+            return reader.read(buf);
+        }
+
+        self.ensure_body()?;
+
+        let n = self.read_body(buf)?;
+        self.pos += n as u64;
+        if matches!(&self.body, Some(body) if body.pos >= body.end) {
+            self.body = None;
+        }
+        Ok(n)
     }
 
     /// Custom implementation to avoid too many `read` calls. The default trait
@@ -377,11 +804,120 @@ impl Seek for S3Reader {
     }
 }
 
+/// Sends a single ranged `GetObject` request and returns once its headers arrive, bounded by
+/// `connect_timeout_ms`, without waiting for or collecting its body
+///
+/// Returns `Err((true, _))` for transient failures worth retrying and `Err((false, _))` for
+/// failures that should fail immediately (e.g. a 404/403).
+async fn send_get_object_once(
+    client: &aws_sdk_s3::Client,
+    bucket: &str,
+    key: &str,
+    from: u64,
+    to: u64,
+    connect_timeout_ms: u64,
+) -> Result<external_types::GetObjectOutput, (bool, S3ReaderError)> {
+    let send_future = client
+        .get_object()
+        .bucket(bucket)
+        .key(key)
+        .range(format!("bytes={}-{}", from, to))
+        .send();
+
+    match tokio::time::timeout(Duration::from_millis(connect_timeout_ms), send_future).await {
+        Ok(Ok(output)) => Ok(output),
+        Ok(Err(err)) => {
+            let retryable = is_retryable_get_error(&err);
+            Err((retryable, S3ReaderError::from(err)))
+        }
+        Err(_) => Err((
+            true,
+            S3ReaderError::ObjectNotFetched("timed out sending GetObject request".to_string()),
+        )),
+    }
+}
+
+/// Sends a single `GetObject` request and collects its body, bounded by `connect_timeout_ms`
+/// and `read_timeout_ms` respectively
+///
+/// Returns `Err((true, _))` for transient failures worth retrying and `Err((false, _))` for
+/// failures that should fail immediately (e.g. a 404/403).
+async fn fetch_range_once(
+    client: &aws_sdk_s3::Client,
+    bucket: &str,
+    key: &str,
+    from: u64,
+    to: u64,
+    connect_timeout_ms: u64,
+    read_timeout_ms: u64,
+) -> Result<external_types::AggregatedBytes, (bool, S3ReaderError)> {
+    let object_output = send_get_object_once(client, bucket, key, from, to, connect_timeout_ms).await?;
+
+    match tokio::time::timeout(Duration::from_millis(read_timeout_ms), object_output.body.collect()).await {
+        Ok(Ok(bytes)) => Ok(bytes),
+        Ok(Err(_)) => Err((true, S3ReaderError::InvalidContent)),
+        Err(_) => Err((true, S3ReaderError::InvalidContent)),
+    }
+}
+
+/// Opens a new ranged `GetObject` body stream, retrying transient failures the same way as
+/// [`S3Reader::read_range`], but without collecting the body into memory first — the caller
+/// drains [`ActiveBody::stream`] incrementally instead
+async fn open_range_stream(
+    client: &aws_sdk_s3::Client,
+    bucket: &str,
+    key: &str,
+    from: u64,
+    to: u64,
+    retry_policy: &RetryPolicy,
+) -> Result<external_types::GetObjectOutput, S3ReaderError> {
+    let mut backoff_ms = retry_policy.retry_initial_backoff_ms;
+    for attempt in 0..=retry_policy.max_single_read_retries {
+        match send_get_object_once(client, bucket, key, from, to, retry_policy.connect_timeout_ms).await {
+            Ok(output) => return Ok(output),
+            Err((false, err)) => return Err(err),
+            Err((true, err)) => {
+                if attempt == retry_policy.max_single_read_retries {
+                    return Err(S3ReaderError::RetriesExhausted(attempt + 1, err.to_string()));
+                }
+                tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+                backoff_ms *= 2;
+            }
+        }
+    }
+    unreachable!("the loop above always returns before exhausting its range")
+}
+
+/// Sends a single `HeadObject` request, bounded by `connect_timeout_ms`
+///
+/// Returns `Err((true, _))` for transient failures worth retrying and `Err((false, _))` for
+/// failures that should fail immediately (e.g. a 404/403).
+async fn fetch_header_once(
+    client: &aws_sdk_s3::Client,
+    bucket: &str,
+    key: &str,
+    connect_timeout_ms: u64,
+) -> Result<external_types::HeadObjectOutput, (bool, S3ReaderError)> {
+    let send_future = client.head_object().bucket(bucket).key(key).send();
+    match tokio::time::timeout(Duration::from_millis(connect_timeout_ms), send_future).await {
+        Ok(Ok(header)) => Ok(header),
+        Ok(Err(err)) => {
+            let retryable = is_retryable_head_error(&err);
+            Err((retryable, S3ReaderError::from(err)))
+        }
+        Err(_) => Err((
+            true,
+            S3ReaderError::ObjectNotFetched("timed out sending HeadObject request".to_string()),
+        )),
+    }
+}
+
 /// Calculates the new cursor for a `Seek` operation
 ///
 /// This function is declared outside of `S3Reader` so that it can be
-/// unit-tested.
-fn s3reader_seek(len: u64, cursor: u64, pos: SeekFrom) -> Result<u64, std::io::Error> {
+/// unit-tested, and so that [`zstd_reader::S3ZstdReader`] can reuse the same cursor
+/// arithmetic against decompressed offsets.
+pub(crate) fn s3reader_seek(len: u64, cursor: u64, pos: SeekFrom) -> Result<u64, std::io::Error> {
     match pos {
         SeekFrom::Start(x) => Ok(std::cmp::min(x, len)),
         SeekFrom::Current(x) => match x >= 0 {
@@ -498,4 +1034,47 @@ mod tests {
         );
         assert!(s3reader_seek(100, 1, std::io::SeekFrom::End(-101)).is_err());
     }
+
+    #[test]
+    fn test_is_retryable_code_matches_transient_errors() {
+        assert!(is_retryable_code(Some("RequestTimeout")));
+        assert!(is_retryable_code(Some("Throttling")));
+        assert!(is_retryable_code(Some("ThrottlingException")));
+        assert!(is_retryable_code(Some("SlowDown")));
+        assert!(is_retryable_code(Some("InternalError")));
+        assert!(is_retryable_code(Some("ServiceUnavailable")));
+    }
+
+    #[test]
+    fn test_is_retryable_code_rejects_permanent_errors_and_none() {
+        assert!(!is_retryable_code(Some("NoSuchKey")));
+        assert!(!is_retryable_code(Some("AccessDenied")));
+        assert!(!is_retryable_code(None));
+    }
+
+    fn dummy_body(pos: u64, end: u64) -> ActiveBody {
+        ActiveBody {
+            stream: Box::pin(tokio::io::empty()),
+            pos,
+            end,
+        }
+    }
+
+    #[test]
+    fn test_active_body_contains_within_window() {
+        let body = dummy_body(10, 20);
+        assert!(body.contains(10));
+        assert!(body.contains(15));
+        assert!(body.contains(19));
+    }
+
+    #[test]
+    fn test_active_body_contains_rejects_outside_window() {
+        let body = dummy_body(10, 20);
+        // before the stream's current read position: already consumed, can't un-read
+        assert!(!body.contains(9));
+        // at or past the window's end: this stream doesn't cover it
+        assert!(!body.contains(20));
+        assert!(!body.contains(100));
+    }
 }
@@ -1,41 +1,55 @@
-#![allow(dead_code)]
-
 use crate::external_types;
 
+/// Whether an S3 key refers to a directory-like prefix, a regular object, or (never, on S3) a symlink
+///
+/// S3 has no native concept of directories. By convention, a key ending in `/` is treated as a
+/// directory marker; every other key is treated as a regular file. See [`Metadata::file_type`].
+pub struct FileType {
+    is_dir: bool,
+}
 
-struct FileType {}
 impl FileType {
     pub fn is_dir(&self) -> bool {
-        // TODO
-        false
+        self.is_dir
     }
 
     pub fn is_file(&self) -> bool {
-        // TODO
-        true
+        !self.is_dir
     }
 
     pub fn is_symlink(&self) -> bool {
-        // TODO
         false
     }
 }
 
-
-struct Permissions {}
+/// S3 objects have no user/group permission model; this always reports read-only
+pub struct Permissions {}
 impl Permissions {
     pub fn readonly(&self) -> bool {
         true
     }
 }
 
-struct Metadata {
-    s3_head: external_types::HeadObjectOutput
+/// Metadata about an S3 object, as returned by a `HeadObject` call
+///
+/// Modeled after [`std::fs::Metadata`].
+pub struct Metadata {
+    key: String,
+    s3_head: external_types::HeadObjectOutput,
 }
 
 impl Metadata {
+    pub(crate) fn new(key: impl Into<String>, s3_head: external_types::HeadObjectOutput) -> Metadata {
+        Metadata {
+            key: key.into(),
+            s3_head,
+        }
+    }
+
     pub fn file_type(&self) -> FileType {
-        FileType {}
+        FileType {
+            is_dir: self.key.ends_with('/'),
+        }
     }
 
     pub fn is_dir(&self) -> bool {
@@ -50,9 +64,10 @@ impl Metadata {
         self.file_type().is_symlink()
     }
 
+    /// Returns the `content_length` of the object, as reported by `HeadObject`
+    #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> u64 {
-        // TODO
-        0
+        u64::try_from(self.s3_head.content_length()).unwrap_or(0)
     }
 
     pub fn permissions(&self) -> Permissions {
@@ -62,4 +77,19 @@ impl Metadata {
     pub fn modified(&self) -> Option<&external_types::DateTime> {
         self.s3_head.last_modified()
     }
-}
\ No newline at end of file
+
+    /// Returns the object's `ETag`
+    pub fn e_tag(&self) -> Option<&str> {
+        self.s3_head.e_tag()
+    }
+
+    /// Returns the object's `Content-Type`
+    pub fn content_type(&self) -> Option<&str> {
+        self.s3_head.content_type()
+    }
+
+    /// Returns the object's storage class, e.g. `STANDARD` or `GLACIER`
+    pub fn storage_class(&self) -> Option<&external_types::StorageClass> {
+        self.s3_head.storage_class()
+    }
+}
@@ -0,0 +1,352 @@
+use std::io::Write;
+
+use thiserror::Error;
+use tokio::runtime::{Handle, Runtime};
+
+use crate::{external_types, RuntimeRef, S3ObjectUri};
+
+/// The smallest part size S3 accepts for a multipart upload, except for the final part
+const MIN_PART_SIZE: usize = 5 * 1024 * 1024;
+
+/// The default part size used by [`S3Writer`], see [`S3Writer::with_part_size`]
+const DEFAULT_PART_SIZE: usize = 8 * 1024 * 1024;
+
+#[derive(Error, Debug)]
+pub enum S3WriterError {
+    #[error("object could not be uploaded: {0}")]
+    ObjectNotUploaded(String),
+    #[error("multipart upload part size must be at least {} bytes", MIN_PART_SIZE)]
+    PartSizeTooSmall,
+    #[error("write called on an S3Writer that has already been finished")]
+    AlreadyFinished,
+}
+
+impl From<S3WriterError> for std::io::Error {
+    fn from(error: S3WriterError) -> std::io::Error {
+        std::io::Error::new(std::io::ErrorKind::Other, error)
+    }
+}
+
+/// State of the multipart upload once it has been started, see [`S3Writer`]
+#[derive(Clone)]
+struct MultipartUpload {
+    upload_id: String,
+    parts: Vec<external_types::CompletedPart>,
+}
+
+/// A Writer for S3 objects that implements `std::io::Write`
+///
+/// `S3Writer` uploads arbitrarily large streams via S3's multipart upload API, so it never
+/// buffers the whole object in memory. Bytes are accumulated in an internal buffer; once that
+/// buffer reaches [`S3Writer::with_part_size`] bytes, a `CreateMultipartUpload` is issued (on
+/// the first such part) followed by an `UploadPart` for that part's bytes. Call
+/// [`S3Writer::finish`] to flush any remaining buffered bytes as the final part (S3 exempts the
+/// final part from the minimum part size) and issue `CompleteMultipartUpload`.
+///
+/// If the writer is dropped without calling [`S3Writer::finish`], any multipart upload that was
+/// already started is aborted via `AbortMultipartUpload`, so it doesn't linger as an orphaned
+/// upload incurring storage charges.
+///
+/// # Example
+/// ```no_run
+/// use std::io::Write;
+/// use s3reader::S3Writer;
+/// use s3reader::S3ObjectUri;
+///
+/// let uri = S3ObjectUri::new("s3://my-bucket/path/to/new/file").unwrap();
+/// let mut writer = S3Writer::new(uri);
+///
+/// writer.write_all(b"hello world").unwrap();
+/// writer.finish().unwrap();
+/// ```
+pub struct S3Writer {
+    client: aws_sdk_s3::Client,
+    uri: S3ObjectUri,
+    runtime: RuntimeRef,
+    part_size: usize,
+    buffer: Vec<u8>,
+    upload: Option<MultipartUpload>,
+    finished: bool,
+}
+
+impl S3Writer {
+    /// Creates a new `S3Writer`.
+    ///
+    /// This constructs and owns a dedicated Tokio runtime for the lifetime of the writer. If you
+    /// already run a Tokio runtime and want to reuse its thread pool instead of spinning up a
+    /// second one, use [`S3Writer::from_config_with_runtime`] instead — see its docs for the
+    /// constraint on where the writer's blocking methods can then be called from.
+    pub fn new(uri: S3ObjectUri) -> S3Writer {
+        let runtime = Runtime::new().unwrap();
+        let config = runtime.block_on(aws_config::load_from_env());
+        S3Writer::build(&config, uri, RuntimeRef::Owned(runtime))
+    }
+
+    /// Creates a new `S3Writer` with a custom AWS `SdkConfig`
+    ///
+    /// This constructs and owns a dedicated Tokio runtime for the lifetime of the writer. If you
+    /// already run a Tokio runtime and want to reuse its thread pool instead of spinning up a
+    /// second one, use [`S3Writer::from_config_with_runtime`] instead — see its docs for the
+    /// constraint on where the writer's blocking methods can then be called from.
+    pub fn from_config(config: &external_types::SdkConfig, uri: S3ObjectUri) -> S3Writer {
+        let runtime = Runtime::new().unwrap();
+        S3Writer::build(config, uri, RuntimeRef::Owned(runtime))
+    }
+
+    /// Creates a new `S3Writer` with a custom AWS `SdkConfig`, sharing a caller-supplied Tokio
+    /// runtime `Handle` instead of owning a dedicated runtime
+    ///
+    /// Use this constructor when you already run a Tokio runtime and want `S3Writer` to reuse
+    /// its thread pool rather than spin up a second one.
+    ///
+    /// `Handle::block_on` panics when called from a task that's already being driven by *any*
+    /// Tokio runtime, not just this one. `S3Writer`'s synchronous `Write`/`finish` methods call
+    /// `block_on` internally, so they must
+    /// only be invoked from a plain blocking thread (e.g. via `tokio::task::spawn_blocking`),
+    /// never directly from inside an `async fn`, even one running on the runtime behind `handle`.
+    pub fn from_config_with_runtime(
+        config: &external_types::SdkConfig,
+        uri: S3ObjectUri,
+        handle: Handle,
+    ) -> S3Writer {
+        S3Writer::build(config, uri, RuntimeRef::Shared(handle))
+    }
+
+    fn build(config: &external_types::SdkConfig, uri: S3ObjectUri, runtime: RuntimeRef) -> S3Writer {
+        let client = aws_sdk_s3::Client::new(config);
+        S3Writer {
+            client,
+            uri,
+            runtime,
+            part_size: DEFAULT_PART_SIZE,
+            buffer: Vec::new(),
+            upload: None,
+            finished: false,
+        }
+    }
+
+    /// Sets the size of each uploaded part, in bytes
+    ///
+    /// Must be at least 5 MiB, S3's minimum multipart upload part size; the final part is
+    /// exempt from that minimum and may be smaller. Defaults to 8 MiB.
+    pub fn with_part_size(mut self, part_size: usize) -> Result<S3Writer, S3WriterError> {
+        if part_size < MIN_PART_SIZE {
+            return Err(S3WriterError::PartSizeTooSmall);
+        }
+        self.part_size = part_size;
+        Ok(self)
+    }
+
+    /// Flushes any remaining buffered bytes as the final part and completes the multipart upload
+    ///
+    /// `finish` takes the writer by value, so it cannot be written to afterwards. If `finish` is
+    /// never called, [`Drop`] aborts the in-progress multipart upload instead of completing it.
+    pub fn finish(mut self) -> Result<(), S3WriterError> {
+        self.finish_mut()
+    }
+
+    fn finish_mut(&mut self) -> Result<(), S3WriterError> {
+        if self.finished {
+            return Ok(());
+        }
+
+        // Upload whatever is left as the final (possibly undersized) part. If nothing was ever
+        // written, this uploads a single empty part so that an empty object is still produced.
+        if !self.buffer.is_empty() || self.upload.is_none() {
+            let data = std::mem::take(&mut self.buffer);
+            if let Err(err) = self.upload_part(data) {
+                self.abort_mut();
+                return Err(err);
+            }
+        }
+
+        // Clone rather than `take` so that a failed `CompleteMultipartUpload` still leaves
+        // `self.upload` populated for `abort_mut` to clean up below.
+        let upload = self
+            .upload
+            .clone()
+            .expect("a multipart upload is always started by the time finish() completes");
+        let handle = self.runtime.handle();
+        match handle.block_on(complete_multipart_upload(&self.client, &self.uri, upload)) {
+            Ok(()) => {
+                self.upload = None;
+                self.finished = true;
+                Ok(())
+            }
+            Err(err) => {
+                self.abort_mut();
+                Err(err)
+            }
+        }
+    }
+
+    /// Aborts the in-progress multipart upload, if any, and marks the writer as finished so
+    /// neither `write` nor `Drop` touch it again
+    fn abort_mut(&mut self) {
+        if let Some(upload) = self.upload.take() {
+            let handle = self.runtime.handle();
+            let _ = handle.block_on(
+                self.client
+                    .abort_multipart_upload()
+                    .bucket(self.uri.bucket())
+                    .key(self.uri.key())
+                    .upload_id(upload.upload_id)
+                    .send(),
+            );
+        }
+        self.finished = true;
+    }
+
+    fn upload_part(&mut self, data: Vec<u8>) -> Result<(), S3WriterError> {
+        let handle = self.runtime.handle();
+        handle.block_on(self.upload_part_async(data))
+    }
+
+    async fn upload_part_async(&mut self, data: Vec<u8>) -> Result<(), S3WriterError> {
+        if self.upload.is_none() {
+            let upload_id = create_multipart_upload(&self.client, &self.uri).await?;
+            self.upload = Some(MultipartUpload {
+                upload_id,
+                parts: Vec::new(),
+            });
+        }
+        let upload = self.upload.as_mut().unwrap();
+        let part_number = upload.parts.len() as i32 + 1;
+
+        let response = self
+            .client
+            .upload_part()
+            .bucket(self.uri.bucket())
+            .key(self.uri.key())
+            .upload_id(&upload.upload_id)
+            .part_number(part_number)
+            .body(external_types::ByteStream::from(data))
+            .send()
+            .await
+            .map_err(|err| S3WriterError::ObjectNotUploaded(err.to_string()))?;
+
+        upload.parts.push(
+            external_types::CompletedPart::builder()
+                .set_e_tag(response.e_tag().map(str::to_string))
+                .part_number(part_number)
+                .build(),
+        );
+        Ok(())
+    }
+}
+
+/// Drains and returns each full `part_size`-sized chunk from the front of `buffer`, leaving
+/// behind whatever doesn't fill a complete part
+fn drain_full_parts(buffer: &mut Vec<u8>, part_size: usize) -> Vec<Vec<u8>> {
+    let mut parts = Vec::new();
+    while buffer.len() >= part_size {
+        parts.push(buffer.drain(..part_size).collect());
+    }
+    parts
+}
+
+impl Write for S3Writer {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, std::io::Error> {
+        if self.finished {
+            return Err(S3WriterError::AlreadyFinished.into());
+        }
+
+        self.buffer.extend_from_slice(buf);
+        for part in drain_full_parts(&mut self.buffer, self.part_size) {
+            self.upload_part(part)?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), std::io::Error> {
+        Ok(())
+    }
+}
+
+impl Drop for S3Writer {
+    /// Aborts the in-progress multipart upload if the writer is dropped without calling
+    /// [`S3Writer::finish`], so that already-uploaded parts don't linger as an orphaned upload.
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+        self.abort_mut();
+    }
+}
+
+async fn create_multipart_upload(
+    client: &aws_sdk_s3::Client,
+    uri: &S3ObjectUri,
+) -> Result<String, S3WriterError> {
+    let response = client
+        .create_multipart_upload()
+        .bucket(uri.bucket())
+        .key(uri.key())
+        .send()
+        .await
+        .map_err(|err| S3WriterError::ObjectNotUploaded(err.to_string()))?;
+
+    response
+        .upload_id()
+        .map(str::to_string)
+        .ok_or_else(|| S3WriterError::ObjectNotUploaded("response is missing an upload id".to_string()))
+}
+
+async fn complete_multipart_upload(
+    client: &aws_sdk_s3::Client,
+    uri: &S3ObjectUri,
+    upload: MultipartUpload,
+) -> Result<(), S3WriterError> {
+    client
+        .complete_multipart_upload()
+        .bucket(uri.bucket())
+        .key(uri.key())
+        .upload_id(upload.upload_id)
+        .multipart_upload(
+            external_types::CompletedMultipartUpload::builder()
+                .set_parts(Some(upload.parts))
+                .build(),
+        )
+        .send()
+        .await
+        .map_err(|err| S3WriterError::ObjectNotUploaded(err.to_string()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drain_full_parts_splits_exact_multiple() {
+        let mut buffer = vec![0u8; 12];
+        let parts = drain_full_parts(&mut buffer, 4);
+        assert_eq!(parts.len(), 3);
+        assert!(parts.iter().all(|part| part.len() == 4));
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_drain_full_parts_leaves_a_partial_remainder() {
+        let mut buffer = vec![0u8; 10];
+        let parts = drain_full_parts(&mut buffer, 4);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(buffer.len(), 2);
+    }
+
+    #[test]
+    fn test_drain_full_parts_below_part_size_drains_nothing() {
+        let mut buffer = vec![0u8; 3];
+        let parts = drain_full_parts(&mut buffer, 4);
+        assert!(parts.is_empty());
+        assert_eq!(buffer.len(), 3);
+    }
+
+    #[test]
+    fn test_drain_full_parts_preserves_order() {
+        let mut buffer: Vec<u8> = (0..9).collect();
+        let parts = drain_full_parts(&mut buffer, 3);
+        assert_eq!(parts, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8]]);
+        assert_eq!(buffer, Vec::<u8>::new());
+    }
+}
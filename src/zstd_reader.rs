@@ -0,0 +1,287 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use crate::{s3reader_seek, S3Reader, S3ReaderError};
+
+/// Magic number of the skippable frame (0xE) that carries the zstd seekable format's seek table
+const SKIPPABLE_FRAME_MAGIC: u32 = 0x184D2A5E;
+
+/// Magic number at the very end of a zstd seekable file, closing the `Seek_Table_Footer`
+const SEEKABLE_MAGIC_NUMBER: u32 = 0x8F92EAB1;
+
+/// Size of the `Seek_Table_Footer`: `Number_Of_Frames` (4) + `Seek_Table_Descriptor` (1) +
+/// `Seekable_Magic_Number` (4)
+const SEEK_TABLE_FOOTER_SIZE: u64 = 9;
+
+/// Size of the skippable frame header preceding the seek table entries: `Magic_Number` (4) +
+/// `Frame_Size` (4)
+const SKIPPABLE_FRAME_HEADER_SIZE: u64 = 8;
+
+/// The parsed seek table of a zstd seekable file
+///
+/// Holds cumulative compressed and decompressed byte offsets per frame, so that locating the
+/// frame containing a given decompressed offset is a binary search rather than a linear scan.
+struct SeekTable {
+    /// `compressed_offsets[i]` is the byte offset of frame `i` in the S3 object;
+    /// `compressed_offsets[num_frames]` is the total compressed size
+    compressed_offsets: Vec<u64>,
+    /// `decompressed_offsets[i]` is the decompressed byte offset at which frame `i` starts;
+    /// `decompressed_offsets[num_frames]` is the total decompressed size
+    decompressed_offsets: Vec<u64>,
+}
+
+impl SeekTable {
+    /// Returns the total decompressed length of the object
+    fn total_len(&self) -> u64 {
+        *self.decompressed_offsets.last().unwrap()
+    }
+
+    /// Returns the index of the frame containing decompressed offset `pos`
+    ///
+    /// `pos` is clamped to the last frame if it falls on or beyond the end of the stream.
+    fn frame_for(&self, pos: u64) -> usize {
+        match self.decompressed_offsets.binary_search(&pos) {
+            Ok(i) => i.min(self.decompressed_offsets.len() - 2),
+            Err(i) => (i - 1).min(self.decompressed_offsets.len() - 2),
+        }
+    }
+
+    /// Returns the inclusive compressed byte range of `frame` within the S3 object
+    fn compressed_range(&self, frame: usize) -> (u64, u64) {
+        (
+            self.compressed_offsets[frame],
+            self.compressed_offsets[frame + 1] - 1,
+        )
+    }
+
+    /// Returns the decompressed offset at which `frame` starts
+    fn decompressed_start(&self, frame: usize) -> u64 {
+        self.decompressed_offsets[frame]
+    }
+}
+
+/// Parses the trailing seek table of a zstd seekable object
+///
+/// The seek table is a skippable frame at the end of the object. Its exact size depends on the
+/// number of frames, which is only known from the `Seek_Table_Footer` at the absolute end of the
+/// object, so this fetches the footer first and then the full trailer in a second ranged read.
+fn read_seek_table(reader: &mut S3Reader) -> Result<SeekTable, S3ReaderError> {
+    let len = reader.len();
+    if len < SEEK_TABLE_FOOTER_SIZE {
+        return Err(S3ReaderError::InvalidSeekTable(
+            "object is too small to contain a seek table footer".to_string(),
+        ));
+    }
+
+    let footer = reader
+        .read_range_sync(len - SEEK_TABLE_FOOTER_SIZE, len - 1)?
+        .into_bytes();
+    let num_frames = u32::from_le_bytes(footer[0..4].try_into().unwrap()) as u64;
+    let descriptor = footer[4];
+    let magic = u32::from_le_bytes(footer[5..9].try_into().unwrap());
+    if magic != SEEKABLE_MAGIC_NUMBER {
+        return Err(S3ReaderError::InvalidSeekTable(format!(
+            "unexpected seekable magic number {:#x}",
+            magic
+        )));
+    }
+
+    let has_checksums = descriptor & 0b1000_0000 != 0;
+    let entry_size: u64 = if has_checksums { 12 } else { 8 };
+    let trailer_size = SKIPPABLE_FRAME_HEADER_SIZE + num_frames * entry_size + SEEK_TABLE_FOOTER_SIZE;
+    if trailer_size > len {
+        return Err(S3ReaderError::InvalidSeekTable(
+            "seek table is larger than the object itself".to_string(),
+        ));
+    }
+
+    let trailer = reader
+        .read_range_sync(len - trailer_size, len - 1)?
+        .into_bytes();
+    let frame_magic = u32::from_le_bytes(trailer[0..4].try_into().unwrap());
+    if frame_magic != SKIPPABLE_FRAME_MAGIC {
+        return Err(S3ReaderError::InvalidSeekTable(format!(
+            "unexpected skippable frame magic number {:#x}",
+            frame_magic
+        )));
+    }
+
+    let mut compressed_offsets = Vec::with_capacity(num_frames as usize + 1);
+    let mut decompressed_offsets = Vec::with_capacity(num_frames as usize + 1);
+    compressed_offsets.push(0);
+    decompressed_offsets.push(0);
+
+    let mut cursor = SKIPPABLE_FRAME_HEADER_SIZE as usize;
+    for _ in 0..num_frames {
+        let compressed_size = u32::from_le_bytes(trailer[cursor..cursor + 4].try_into().unwrap()) as u64;
+        let decompressed_size =
+            u32::from_le_bytes(trailer[cursor + 4..cursor + 8].try_into().unwrap()) as u64;
+        compressed_offsets.push(compressed_offsets.last().unwrap() + compressed_size);
+        decompressed_offsets.push(decompressed_offsets.last().unwrap() + decompressed_size);
+        cursor += entry_size as usize;
+    }
+
+    Ok(SeekTable {
+        compressed_offsets,
+        decompressed_offsets,
+    })
+}
+
+/// A Reader for the *decompressed* contents of an S3 object stored in the zstd seekable format
+///
+/// The zstd seekable format stores a payload as a sequence of independently-compressed zstd
+/// frames, followed by a skippable seek-table frame listing each frame's compressed and
+/// decompressed size. `S3ZstdReader` parses that seek table once on open, then serves `Read`
+/// and `Seek` against decompressed offsets by range-reading and decompressing exactly the frame
+/// that contains the requested offset.
+///
+/// # Example
+/// ```no_run
+/// use std::io::{Read, Seek};
+/// use s3reader::{S3ObjectUri, S3ZstdReader};
+///
+/// let uri = S3ObjectUri::new("s3://my-bucket/path/to/huge/file.zst").unwrap();
+/// let mut reader = S3ZstdReader::open(uri).unwrap();
+///
+/// reader.seek(std::io::SeekFrom::Start(100)).unwrap();
+///
+/// let mut buf: Vec<u8> = [0; 1024].to_vec();
+/// reader.read(&mut buf).expect("Error reading from S3");
+/// ```
+pub struct S3ZstdReader {
+    reader: S3Reader,
+    seek_table: SeekTable,
+    pos: u64,
+    /// The most recently decompressed frame, so sequential reads within one frame don't re-fetch
+    cached_frame: Option<(usize, Vec<u8>)>,
+}
+
+impl S3ZstdReader {
+    /// Opens an `S3ZstdReader` for the given URI, fetching and parsing its seek table
+    pub fn open(uri: crate::S3ObjectUri) -> Result<S3ZstdReader, S3ReaderError> {
+        S3ZstdReader::from_reader(S3Reader::open(uri)?)
+    }
+
+    /// Wraps an already-open `S3Reader`, fetching and parsing its seek table
+    pub fn from_reader(mut reader: S3Reader) -> Result<S3ZstdReader, S3ReaderError> {
+        let seek_table = read_seek_table(&mut reader)?;
+        Ok(S3ZstdReader {
+            reader,
+            seek_table,
+            pos: 0,
+            cached_frame: None,
+        })
+    }
+
+    /// Returns the total decompressed length of the object
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> u64 {
+        self.seek_table.total_len()
+    }
+
+    /// Ensures `frame` is decompressed and held in `cached_frame`, fetching it if necessary
+    ///
+    /// Frames are read and decompressed whole: the seekable format requires a full frame's
+    /// compressed bytes to decompress it, so a partial-frame fetch would be invalid.
+    fn ensure_frame(&mut self, frame: usize) -> Result<(), std::io::Error> {
+        if matches!(&self.cached_frame, Some((cached, _)) if *cached == frame) {
+            return Ok(());
+        }
+        let (from, to) = self.seek_table.compressed_range(frame);
+        let compressed = self.reader.read_range_sync(from, to)?.into_bytes();
+        let decompressed = zstd::stream::decode_all(&compressed[..])?;
+        self.cached_frame = Some((frame, decompressed));
+        Ok(())
+    }
+}
+
+impl Read for S3ZstdReader {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, std::io::Error> {
+        let total_len = self.len();
+        if self.pos >= total_len {
+            return Ok(0);
+        }
+
+        let frame = self.seek_table.frame_for(self.pos);
+        self.ensure_frame(frame)?;
+
+        let frame_start = self.seek_table.decompressed_start(frame);
+        let (_, data) = self.cached_frame.as_ref().unwrap();
+        let offset = (self.pos - frame_start) as usize;
+        let available = &data[offset..];
+
+        let n = std::cmp::min(buf.len(), available.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl Seek for S3ZstdReader {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64, std::io::Error> {
+        match s3reader_seek(self.len(), self.pos, pos) {
+            Ok(x) => {
+                self.pos = x;
+                Ok(x)
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Three frames of decompressed sizes 10, 20, 30 (compressed sizes 1, 2, 3)
+    fn seek_table() -> SeekTable {
+        SeekTable {
+            compressed_offsets: vec![0, 1, 3, 6],
+            decompressed_offsets: vec![0, 10, 30, 60],
+        }
+    }
+
+    #[test]
+    fn test_total_len() {
+        assert_eq!(seek_table().total_len(), 60);
+    }
+
+    #[test]
+    fn test_frame_for_exact_boundaries() {
+        let table = seek_table();
+        assert_eq!(table.frame_for(0), 0);
+        assert_eq!(table.frame_for(10), 1);
+        assert_eq!(table.frame_for(30), 2);
+    }
+
+    #[test]
+    fn test_frame_for_within_frame() {
+        let table = seek_table();
+        assert_eq!(table.frame_for(5), 0);
+        assert_eq!(table.frame_for(15), 1);
+        assert_eq!(table.frame_for(59), 2);
+    }
+
+    #[test]
+    fn test_frame_for_clamps_past_the_end() {
+        // a position at or beyond the total decompressed length is clamped to the last frame
+        let table = seek_table();
+        assert_eq!(table.frame_for(60), 2);
+        assert_eq!(table.frame_for(1000), 2);
+    }
+
+    #[test]
+    fn test_compressed_range() {
+        let table = seek_table();
+        assert_eq!(table.compressed_range(0), (0, 0));
+        assert_eq!(table.compressed_range(1), (1, 2));
+        assert_eq!(table.compressed_range(2), (3, 5));
+    }
+
+    #[test]
+    fn test_decompressed_start() {
+        let table = seek_table();
+        assert_eq!(table.decompressed_start(0), 0);
+        assert_eq!(table.decompressed_start(1), 10);
+        assert_eq!(table.decompressed_start(2), 30);
+    }
+}